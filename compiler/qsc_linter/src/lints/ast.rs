@@ -28,6 +28,7 @@ declare_ast_lints! {
     (NeedlessParens, LintLevel::Allow, "unnecessary parentheses", "remove the extra parentheses for clarity"),
     (RedundantSemicolons, LintLevel::Warn, "redundant semicolons", "remove the redundant semicolons"),
     (DeprecatedNewtype, LintLevel::Allow, "deprecated `newtype` declarations", "`newtype` declarations are deprecated, use `struct` instead"),
+    (UnreachableStatement, LintLevel::Warn, "unreachable statement", "remove the unreachable statement"),
 }
 
 impl AstLintPass for DivisionByZero {
@@ -60,6 +61,34 @@ impl NeedlessParens {
         }
     }
 
+    /// Flags a parenthesized expression that is redundant regardless of any
+    /// surrounding operator precedence, e.g. the condition of an `if`/`while` or
+    /// the sole operand of `return`/`fail`.
+    fn push_always(&self, child: &Expr, buffer: &mut Vec<Lint>) {
+        if let ExprKind::Paren(_) = &*child.kind {
+            buffer.push(lint!(
+                self,
+                child.span,
+                Self::get_code_action_edits(child.span)
+            ));
+        }
+    }
+
+    /// Flags a parenthesized single argument or index expression. Parentheses
+    /// around a tuple are left alone because they are the tuple's own delimiters,
+    /// not redundant grouping.
+    fn push_non_tuple(&self, child: &Expr, buffer: &mut Vec<Lint>) {
+        if let ExprKind::Paren(inner) = &*child.kind {
+            if !matches!(&*inner.kind, ExprKind::Tuple(_)) {
+                buffer.push(lint!(
+                    self,
+                    child.span,
+                    Self::get_code_action_edits(child.span)
+                ));
+            }
+        }
+    }
+
     /// Returns the code action edits that strip out the first and last characters for the given span.
     fn get_code_action_edits(span: Span) -> Vec<(String, Span)> {
         vec![
@@ -91,6 +120,30 @@ impl AstLintPass for NeedlessParens {
             ExprKind::Assign(_, right) | ExprKind::AssignOp(_, _, right) => {
                 self.push(expr, right, buffer);
             }
+            // `if (cond)`, `elif (cond)` (nested in the else branch), and `while (cond)`
+            // never need the parentheses around the condition.
+            ExprKind::If(cond, _, _) | ExprKind::While(cond, _) => {
+                self.push_always(cond, buffer);
+            }
+            // `repeat { } until (cond)` likewise never needs them.
+            ExprKind::Repeat(_, cond, _) => {
+                self.push_always(cond, buffer);
+            }
+            // The sole operand of `return`/`fail` is already delimited by the keyword.
+            ExprKind::Return(operand) | ExprKind::Fail(operand) => {
+                self.push_always(operand, buffer);
+            }
+            // The parentheses around a single call argument are the call's required
+            // delimiter (`f a` is not valid Q#), so only the *inner* parentheses of
+            // `f((a))` are redundant: unwrap the delimiter and lint what's inside.
+            ExprKind::Call(_, arg) => {
+                if let ExprKind::Paren(inner) = &*arg.kind {
+                    self.push_non_tuple(inner, buffer);
+                }
+            }
+            // An index stores a bare expression (`arr[i]`), so `arr[(i)]` is redundant;
+            // a tuple index is left alone.
+            ExprKind::Index(_, index) => self.push_non_tuple(index, buffer),
             _ => (),
         }
     }
@@ -110,11 +163,28 @@ impl AstLintPass for NeedlessParens {
 }
 
 impl RedundantSemicolons {
-    /// Helper function that pushes a lint to the buffer if we have
-    /// found two or more semicolons.
-    fn maybe_push(&self, seq: &mut Option<Span>, buffer: &mut Vec<Lint>) {
-        if let Some(span) = seq.take() {
-            buffer.push(lint!(self, span, vec![(String::new(), span)]));
+    /// Helper function that pushes a lint to the buffer if we have found one or
+    /// more redundant semicolons. The message and help text are pluralized
+    /// according to whether the merged run collapsed a single semicolon or
+    /// several, so the hover and the quickfix read correctly for `x;;` versus
+    /// `x;;;`.
+    fn maybe_push(&self, seq: &mut Option<(Span, bool)>, buffer: &mut Vec<Lint>) {
+        if let Some((span, multiple)) = seq.take() {
+            let (message, help) = if multiple {
+                ("unnecessary trailing semicolons", "remove these semicolons")
+            } else {
+                ("unnecessary trailing semicolon", "remove this semicolon")
+            };
+            // `Lint::message`/`help` are public `&'static str` fields, so overriding
+            // them on the macro-built lint is the only way to specialize the static
+            // text from the `declare_ast_lints!` table without a dedicated `lint!`
+            // arm (which lives in the linter macro module, not this file). If those
+            // field types ever change, this assignment fails to compile rather than
+            // silently reverting to the table wording.
+            let mut lint = lint!(self, span, vec![(String::new(), span)]);
+            lint.message = message;
+            lint.help = help;
+            buffer.push(lint);
         }
     }
 }
@@ -125,15 +195,24 @@ impl AstLintPass for RedundantSemicolons {
     /// statements in a row, we group them as single lint, that spans from
     /// the first redundant semicolon to the last redundant semicolon.
     fn check_block(&self, block: &Block, buffer: &mut Vec<Lint>) {
-        // a finite state machine that keeps track of the span of the redundant semicolons
+        // a finite state machine that keeps track of the span of the redundant
+        // semicolons, and whether the run collapsed more than one of them.
         // None: no redundant semicolons
-        // Some(_): one or more redundant semicolons
-        let mut seq: Option<Span> = None;
+        // Some((_, false)): exactly one redundant semicolon
+        // Some((_, true)): two or more redundant semicolons
+        let mut seq: Option<(Span, bool)> = None;
 
         for stmt in block.stmts.iter() {
             match (&*stmt.kind, &mut seq) {
-                (StmtKind::Empty, None) => seq = Some(stmt.span),
-                (StmtKind::Empty, Some(span)) => span.hi = stmt.span.hi,
+                // Skip compiler-synthesized empty statements carrying a zero-width
+                // or dummy span so the quickfix never emits an empty edit at an
+                // invalid location.
+                (StmtKind::Empty, _) if stmt.span.lo >= stmt.span.hi => {}
+                (StmtKind::Empty, None) => seq = Some((stmt.span, false)),
+                (StmtKind::Empty, Some((span, multiple))) => {
+                    span.hi = stmt.span.hi;
+                    *multiple = true;
+                }
                 (_, seq) => self.maybe_push(seq, buffer),
             }
         }
@@ -142,6 +221,52 @@ impl AstLintPass for RedundantSemicolons {
     }
 }
 
+impl UnreachableStatement {
+    /// Returns `true` if executing `stmt` unconditionally diverges, i.e. no
+    /// statement after it in the same block can ever run. In Q# the divergent
+    /// forms are `return` and `fail` expressions, whether they appear as an
+    /// expression statement or followed by a semicolon.
+    fn is_divergent(stmt: &Stmt) -> bool {
+        let expr = match &*stmt.kind {
+            StmtKind::Expr(expr) | StmtKind::Semi(expr) => expr,
+            _ => return false,
+        };
+        matches!(&*expr.kind, ExprKind::Return(..) | ExprKind::Fail(..))
+    }
+}
+
+impl AstLintPass for UnreachableStatement {
+    /// Flags statements that follow a statement that unconditionally diverges.
+    /// We walk the block in order and, once a divergent statement is seen, merge
+    /// the spans of every subsequent statement into a single run so the whole
+    /// dead tail is reported as one lint. Nested blocks are handled naturally
+    /// because `check_block` fires once per block.
+    ///
+    /// This lint opts out of a quickfix: deleting statements can drop binding
+    /// sites that code before the divergent statement still refers to, so we
+    /// surface the diagnostic only and leave the removal to the author.
+    fn check_block(&self, block: &Block, buffer: &mut Vec<Lint>) {
+        // the merged span of the unreachable tail, once we've diverged
+        let mut unreachable: Option<Span> = None;
+        let mut diverged = false;
+
+        for stmt in block.stmts.iter() {
+            if diverged {
+                match &mut unreachable {
+                    None => unreachable = Some(stmt.span),
+                    Some(span) => span.hi = stmt.span.hi,
+                }
+            } else if Self::is_divergent(stmt) {
+                diverged = true;
+            }
+        }
+
+        if let Some(span) = unreachable {
+            buffer.push(lint!(self, span));
+        }
+    }
+}
+
 fn precedence(expr: &Expr) -> u8 {
     match &*expr.kind {
         ExprKind::Lit(_) => 15,
@@ -0,0 +1,269 @@
+// Copyright (c) Microsoft Corporation.
+// Licensed under the MIT License.
+
+use crate::{Lint, LintLevel, linter::ast::run_ast_lints};
+use expect_test::{Expect, expect};
+use qsc_data_structures::span::Span;
+
+/// A flattened view of a [`Lint`] that renders the offending source text
+/// instead of raw offsets, so the expect snapshots stay readable and stable
+/// across unrelated edits elsewhere in the wrapper.
+#[derive(Debug)]
+struct SrcLint {
+    source: String,
+    level: LintLevel,
+    message: &'static str,
+    help: &'static str,
+}
+
+impl SrcLint {
+    fn from_lint(lint: &Lint, source: &str) -> Self {
+        Self {
+            source: slice(source, lint.span),
+            level: lint.level,
+            message: lint.message,
+            help: lint.help,
+        }
+    }
+}
+
+fn slice(source: &str, span: Span) -> String {
+    source[span.lo as usize..span.hi as usize].to_string()
+}
+
+/// Wraps `fragment` in a minimal operation body, runs every AST lint over it
+/// (promoting `Allow`-by-default lints so `NeedlessParens` is exercised), and
+/// asserts the rendered diagnostics against `expected`.
+fn check(fragment: &str, expected: &Expect) {
+    let source = format!("namespace Test {{ operation Main() : Unit {{ {fragment} }} }}");
+    let lints = run_ast_lints(&source, LintLevel::Warn);
+    let actual: Vec<SrcLint> = lints.iter().map(|l| SrcLint::from_lint(l, &source)).collect();
+    expected.assert_debug_eq(&actual);
+}
+
+#[test]
+fn unreachable_statement_after_return() {
+    check(
+        "return 1; let x = 2;",
+        &expect![[r#"
+            [
+                SrcLint {
+                    source: "let x = 2;",
+                    level: Warn,
+                    message: "unreachable statement",
+                    help: "remove the unreachable statement",
+                },
+            ]
+        "#]],
+    );
+}
+
+#[test]
+fn unreachable_statements_merge_into_one_run() {
+    check(
+        "fail \"bad\"; let x = 2; let y = 3;",
+        &expect![[r#"
+            [
+                SrcLint {
+                    source: "let x = 2; let y = 3;",
+                    level: Warn,
+                    message: "unreachable statement",
+                    help: "remove the unreachable statement",
+                },
+            ]
+        "#]],
+    );
+}
+
+#[test]
+fn divergent_statement_as_tail_is_not_flagged() {
+    check(
+        "let x = 1; return x;",
+        &expect![[r#"
+            []
+        "#]],
+    );
+}
+
+#[test]
+fn needless_parens_in_if_condition() {
+    check(
+        "if (true) { }",
+        &expect![[r#"
+            [
+                SrcLint {
+                    source: "(true)",
+                    level: Warn,
+                    message: "unnecessary parentheses",
+                    help: "remove the extra parentheses for clarity",
+                },
+            ]
+        "#]],
+    );
+}
+
+#[test]
+fn needless_parens_in_while_condition() {
+    check(
+        "while (true) { }",
+        &expect![[r#"
+            [
+                SrcLint {
+                    source: "(true)",
+                    level: Warn,
+                    message: "unnecessary parentheses",
+                    help: "remove the extra parentheses for clarity",
+                },
+            ]
+        "#]],
+    );
+}
+
+#[test]
+fn needless_parens_in_repeat_until_condition() {
+    check(
+        "repeat { } until (true);",
+        &expect![[r#"
+            [
+                SrcLint {
+                    source: "(true)",
+                    level: Warn,
+                    message: "unnecessary parentheses",
+                    help: "remove the extra parentheses for clarity",
+                },
+            ]
+        "#]],
+    );
+}
+
+#[test]
+fn needless_parens_in_return_operand() {
+    check(
+        "return (1);",
+        &expect![[r#"
+            [
+                SrcLint {
+                    source: "(1)",
+                    level: Warn,
+                    message: "unnecessary parentheses",
+                    help: "remove the extra parentheses for clarity",
+                },
+            ]
+        "#]],
+    );
+}
+
+#[test]
+fn needless_parens_in_fail_operand() {
+    check(
+        "fail (\"bad\");",
+        &expect![[r#"
+            [
+                SrcLint {
+                    source: "(\"bad\")",
+                    level: Warn,
+                    message: "unnecessary parentheses",
+                    help: "remove the extra parentheses for clarity",
+                },
+            ]
+        "#]],
+    );
+}
+
+#[test]
+fn needless_parens_inner_of_single_call_argument() {
+    check(
+        "let _ = Microsoft.Quantum.Math.AbsI((1));",
+        &expect![[r#"
+            [
+                SrcLint {
+                    source: "(1)",
+                    level: Warn,
+                    message: "unnecessary parentheses",
+                    help: "remove the extra parentheses for clarity",
+                },
+            ]
+        "#]],
+    );
+}
+
+#[test]
+fn needless_parens_in_index() {
+    check(
+        "let xs = [1, 2, 3]; let _ = xs[(0)];",
+        &expect![[r#"
+            [
+                SrcLint {
+                    source: "(0)",
+                    level: Warn,
+                    message: "unnecessary parentheses",
+                    help: "remove the extra parentheses for clarity",
+                },
+            ]
+        "#]],
+    );
+}
+
+#[test]
+fn mandatory_call_delimiter_is_not_flagged() {
+    check(
+        "let _ = Microsoft.Quantum.Math.AbsI(1);",
+        &expect![[r#"
+            []
+        "#]],
+    );
+}
+
+#[test]
+fn tuple_parens_are_not_flagged() {
+    check(
+        "return (1, 2);",
+        &expect![[r#"
+            []
+        "#]],
+    );
+}
+
+#[test]
+fn tuple_call_argument_is_not_flagged() {
+    check(
+        "let _ = Microsoft.Quantum.Math.MaxI((1, 2));",
+        &expect![[r#"
+            []
+        "#]],
+    );
+}
+
+#[test]
+fn single_redundant_semicolon_is_singular() {
+    check(
+        "let x = 1;;",
+        &expect![[r#"
+            [
+                SrcLint {
+                    source: ";",
+                    level: Warn,
+                    message: "unnecessary trailing semicolon",
+                    help: "remove this semicolon",
+                },
+            ]
+        "#]],
+    );
+}
+
+#[test]
+fn multiple_redundant_semicolons_are_plural() {
+    check(
+        "let x = 1;;;",
+        &expect![[r#"
+            [
+                SrcLint {
+                    source: ";;",
+                    level: Warn,
+                    message: "unnecessary trailing semicolons",
+                    help: "remove these semicolons",
+                },
+            ]
+        "#]],
+    );
+}